@@ -27,7 +27,18 @@
 //! 30.06 GiB/s
 //! 29.33 GiB/s
 //! ```
+//!
+//! # `no_std` support
+//!
+//! The `std` feature is enabled by default and pulls in `println!`/`eprintln!`-based and
+//! `mpsc`-based reporting. Disabling it (`default-features = false`) switches every module that
+//! wraps a reader or writer (`read`, `bufread`, `write`) over to this crate's own [`io`] module,
+//! a narrow stand-in for `std::io`'s traits, and drops each down to the single, always-available
+//! `slot` constructor, which reports through a plain closure instead. Since there's no
+//! thread-local clock without `std`, timing is abstracted behind the [`Clock`] trait, which
+//! callers implement for their platform's monotonic timer.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![doc(html_root_url = "https://docs.rs/nyx/latest")]
 #![deny(
     bad_style,
@@ -39,13 +50,22 @@
     unstable_features
 )]
 
+#[cfg(feature = "std")]
 use std::cell::Cell;
-use std::fmt::{self, Display, Formatter};
-use std::time::{Duration, Instant};
+#[cfg(feature = "std")]
+use std::time::Instant;
 
+use core::fmt::{self, Display, Formatter};
+use core::time::Duration;
+
+#[cfg(feature = "std")]
 thread_local!(static INTERVAL: Cell<Duration> = Cell::new(Duration::from_secs(1)));
 
 /// Gets the update interval for the current thread.
+///
+/// Only available with the `std` feature, since it relies on thread-local storage. `no_std`
+/// callers pass their interval directly to [`read::slot`]/[`write::slot`] instead.
+#[cfg(feature = "std")]
 #[inline]
 pub fn get() -> Duration {
     INTERVAL.with(Cell::get)
@@ -54,13 +74,191 @@ pub fn get() -> Duration {
 /// Sets the update interval for the current thread.
 ///
 /// By default the interval is one second.
+#[cfg(feature = "std")]
 #[inline]
 pub fn set(interval: Duration) {
     INTERVAL.with(move |c| c.set(interval));
 }
 
+#[cfg(feature = "std")]
+thread_local!(static TAU: Cell<Duration> = Cell::new(Duration::from_secs(3)));
+
+/// Gets the EWMA time constant for the current thread, used by `slot_ewma` constructors such
+/// as [`read::slot_ewma`].
+///
+/// Only available with the `std` feature, since it relies on thread-local storage. `no_std`
+/// callers pass their time constant directly to the `slot_ewma` constructors instead.
+#[cfg(feature = "std")]
+#[inline]
+pub fn tau() -> Duration {
+    TAU.with(Cell::get)
+}
+
+/// Sets the EWMA time constant for the current thread.
+///
+/// By default this is three seconds.
+#[cfg(feature = "std")]
+#[inline]
+pub fn set_tau(tau: Duration) {
+    TAU.with(move |c| c.set(tau));
+}
+
+/// A monotonic clock used to time throughput intervals.
+///
+/// [`std::time::Instant`] implements this when the `std` feature is enabled, which is what
+/// every constructor in this crate uses by default. `no_std` targets have no such thing to
+/// reach for, so [`read::slot`] and [`write::slot`] are generic over `Clock` and let the
+/// caller supply their own monotonic source, e.g. a hardware timer or cycle counter.
+pub trait Clock: Copy {
+    /// Returns an instant representing the current moment.
+    fn now() -> Self;
+
+    /// Returns the time elapsed since this instant was captured.
+    fn elapsed(&self) -> Duration;
+}
+
+#[cfg(feature = "std")]
+impl Clock for Instant {
+    #[inline]
+    fn now() -> Self {
+        Instant::now()
+    }
+
+    #[inline]
+    fn elapsed(&self) -> Duration {
+        Instant::elapsed(self)
+    }
+}
+
+/// A `no_std` stand-in for the handful of `std::io` items the `read`, `bufread`, and `write`
+/// modules need.
+///
+/// `core_io` is the usual crate reached for here, but it hasn't built against a current
+/// toolchain in years: its build script only recognizes rustc versions up to 2021-03-25, and the
+/// crate itself relies on several nightly-only features that have since been removed. Since the
+/// actual surface needed is just `read`/`read_vectored`, `write`/`write_vectored`/`flush`, and
+/// `fill_buf`/`consume`, it's simpler to define that narrow slice directly than to depend on an
+/// abandoned crate.
+#[cfg(not(feature = "std"))]
+pub mod io {
+    use core::fmt;
+
+    /// An I/O error, carrying a human-readable description.
+    ///
+    /// `no_std` targets have no universal errno/OS-error representation to normalize onto, so
+    /// this is just a message, the same way an implementor would describe whatever underlying
+    /// fault it hit.
+    #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+    pub struct Error(pub &'static str);
+
+    impl fmt::Display for Error {
+        #[inline]
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    /// Shorthand for `Result<T, Error>`, mirroring `std::io::Result`.
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    /// A mutable memory buffer used for vectored reads, mirroring `std::io::IoSliceMut`.
+    #[repr(transparent)]
+    pub struct IoSliceMut<'a>(&'a mut [u8]);
+
+    impl<'a> IoSliceMut<'a> {
+        /// Creates a new `IoSliceMut` wrapping a byte slice.
+        #[inline]
+        pub fn new(buf: &'a mut [u8]) -> Self {
+            IoSliceMut(buf)
+        }
+    }
+
+    impl<'a> core::ops::Deref for IoSliceMut<'a> {
+        type Target = [u8];
+
+        #[inline]
+        fn deref(&self) -> &[u8] {
+            self.0
+        }
+    }
+
+    impl<'a> core::ops::DerefMut for IoSliceMut<'a> {
+        #[inline]
+        fn deref_mut(&mut self) -> &mut [u8] {
+            self.0
+        }
+    }
+
+    /// An immutable memory buffer used for vectored writes, mirroring `std::io::IoSlice`.
+    #[repr(transparent)]
+    pub struct IoSlice<'a>(&'a [u8]);
+
+    impl<'a> IoSlice<'a> {
+        /// Creates a new `IoSlice` wrapping a byte slice.
+        #[inline]
+        pub fn new(buf: &'a [u8]) -> Self {
+            IoSlice(buf)
+        }
+    }
+
+    impl<'a> core::ops::Deref for IoSlice<'a> {
+        type Target = [u8];
+
+        #[inline]
+        fn deref(&self) -> &[u8] {
+            self.0
+        }
+    }
+
+    /// A `no_std` stand-in for `std::io::Read`: just `read` and `read_vectored`, with the same
+    /// default-impl relationship between them as `std::io::Read`.
+    pub trait Read {
+        /// Pulls some bytes from this source into `buf`, returning the number read.
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize>;
+
+        /// Like `read`, but into a discontiguous set of buffers, filling the first non-empty one.
+        #[inline]
+        fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> Result<usize> {
+            bufs.iter_mut()
+                .find(|buf| !buf.is_empty())
+                .map_or(Ok(0), |buf| self.read(buf))
+        }
+    }
+
+    /// A `no_std` stand-in for `std::io::Write`: `write`, `write_vectored`, and `flush`.
+    pub trait Write {
+        /// Writes some bytes from `buf` into this sink, returning the number written.
+        fn write(&mut self, buf: &[u8]) -> Result<usize>;
+
+        /// Like `write`, but from a discontiguous set of buffers, writing the first non-empty
+        /// one.
+        #[inline]
+        fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> Result<usize> {
+            bufs.iter()
+                .find(|buf| !buf.is_empty())
+                .map_or(Ok(0), |buf| self.write(buf))
+        }
+
+        /// Flushes any buffered output.
+        fn flush(&mut self) -> Result<()>;
+    }
+
+    /// A `no_std` stand-in for `std::io::BufRead`: `fill_buf` and `consume`.
+    pub trait BufRead: Read {
+        /// Returns the contents of the internal buffer, filling it from the underlying source if
+        /// it's empty.
+        fn fill_buf(&mut self) -> Result<&[u8]>;
+
+        /// Marks `amt` bytes of the buffer as consumed, so they aren't returned again.
+        fn consume(&mut self, amt: usize);
+    }
+}
+
 /// Bytes per second with expected formatting.
 ///
+/// The `Display` impl always uses binary units ([`BpsStyle::Binary`]); call [`Bps::format`] to
+/// choose decimal (SI) units or a bit-based rate instead.
+///
 /// # Examples
 /// ```
 /// # use nyx::Bps;
@@ -69,45 +267,246 @@ pub fn set(interval: Duration) {
 /// assert_eq!(Bps(1_048_576).to_string(), "1.00 MiB/s");
 /// assert_eq!(Bps(1_073_741_824).to_string(), "1.00 GiB/s");
 /// assert_eq!(Bps(1_099_511_627_776).to_string(), "1.00 TiB/s");
+/// assert_eq!(Bps(1_125_899_906_842_624).to_string(), "1.00 PiB/s");
+/// assert_eq!(Bps(1_152_921_504_606_846_976).to_string(), "1.00 EiB/s");
 /// ```
 #[derive(Copy, Clone, Debug, Default, Hash, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Bps(pub u64);
 
+impl Bps {
+    /// Formats this rate using the given [`BpsStyle`], instead of the default binary-unit
+    /// `Display` impl.
+    ///
+    /// # Examples
+    /// ```
+    /// # use nyx::{Bps, BpsStyle};
+    /// assert_eq!(Bps(1_000_000).format(BpsStyle::Decimal).to_string(), "1.00 MB/s");
+    /// assert_eq!(Bps(1_000_000).format(BpsStyle::Bits).to_string(), "8.00 Mbit/s");
+    /// ```
+    #[inline]
+    pub fn format(self, style: BpsStyle) -> Formatted {
+        Formatted(self, style)
+    }
+}
+
 impl Display for Bps {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        let n = self.0 as f64;
-        match self.0 {
-            0..=1023 => write!(f, "{:.2} B/s", n),
-            1024..=1_048_575 => write!(f, "{:.2} KiB/s", n / 1024.0),
-            1_048_576..=1_073_741_823 => write!(f, "{:.2} MiB/s", n / 1_048_576.0),
-            1_073_741_824..=1_099_511_627_775 => write!(f, "{:.2} GiB/s", n / 1_073_741_824.0),
-            1_099_511_627_776..=18_446_744_073_709_551_615 => {
-                write!(f, "{:.2} TiB/s", n / 1_099_511_627_776.0)
-            }
+        self.format(BpsStyle::Binary).fmt(f)
+    }
+}
+
+/// The unit system used to format a [`Bps`] rate or a [`Summary`]'s total.
+///
+/// See [`Bps::format`] and [`Summary::format`].
+#[derive(Copy, Clone, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub enum BpsStyle {
+    /// Binary byte units (KiB, MiB, GiB, TiB, PiB, EiB), powers of 1024. This is what `Bps`'s
+    /// `Display` impl uses.
+    Binary,
+    /// Decimal (SI) byte units (kB, MB, GB, TB, PB, EB), powers of 1000, as commonly reported
+    /// by network tools.
+    Decimal,
+    /// Decimal (SI) bit units (kbit, Mbit, Gbit, Tbit, Pbit, Ebit), powers of 1000, multiplying
+    /// the byte count by 8.
+    Bits,
+}
+
+const BINARY_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+const DECIMAL_UNITS: [&str; 7] = ["B", "kB", "MB", "GB", "TB", "PB", "EB"];
+const BIT_UNITS: [&str; 7] = ["bit", "kbit", "Mbit", "Gbit", "Tbit", "Pbit", "Ebit"];
+
+impl BpsStyle {
+    /// The base and unit tier names used by this style, smallest tier first.
+    #[inline]
+    fn base_and_units(self) -> (f64, &'static [&'static str; 7]) {
+        match self {
+            BpsStyle::Binary => (1024.0, &BINARY_UNITS),
+            BpsStyle::Decimal => (1000.0, &DECIMAL_UNITS),
+            BpsStyle::Bits => (1000.0, &BIT_UNITS),
+        }
+    }
+
+    /// The amount to format, in this style's base unit, for `bytes` bytes.
+    #[inline]
+    fn amount(self, bytes: u64) -> f64 {
+        match self {
+            BpsStyle::Bits => bytes as f64 * 8.0,
+            BpsStyle::Binary | BpsStyle::Decimal => bytes as f64,
+        }
+    }
+}
+
+/// Formats `n`, already in the style's base unit, by climbing `units` one tier per `base`.
+#[inline]
+fn format_amount(f: &mut Formatter, mut n: f64, base: f64, units: &[&str; 7]) -> fmt::Result {
+    let mut tier = 0;
+    while n >= base && tier + 1 < units.len() {
+        n /= base;
+        tier += 1;
+    }
+    write!(f, "{:.2} {}", n, units[tier])
+}
+
+/// A [`Display`] adapter that formats a [`Bps`] in a particular [`BpsStyle`].
+///
+/// Created by [`Bps::format`].
+#[derive(Copy, Clone, Debug)]
+pub struct Formatted(Bps, BpsStyle);
+
+impl Display for Formatted {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (base, units) = self.1.base_and_units();
+        format_amount(f, self.1.amount(self.0 .0), base, units)?;
+        write!(f, "/s")
+    }
+}
+
+/// Cumulative bytes transferred and elapsed time, for an end-of-stream summary.
+///
+/// Unlike [`Bps`], which a reporting adapter's slot receives on every tick with the rate
+/// measured over that interval, a `Summary` describes a whole transfer and formats as a
+/// "N GiB in Ms (R/s avg)" line. [`copy::summary`] and [`copy::with_capacity_summary`] return
+/// one when the copy completes; the `read`, `bufread`, and `write` adapters do not produce one,
+/// so a caller using them fills in `bytes` and `elapsed` from its own counts and clock.
+///
+/// # Examples
+/// ```
+/// # use nyx::{BpsStyle, Summary};
+/// use std::time::Duration;
+///
+/// let summary = Summary {
+///     bytes: 1_048_576,
+///     elapsed: Duration::from_secs(1),
+/// };
+/// assert_eq!(summary.to_string(), "1.00 MiB in 1.00s (1.00 MiB/s avg)");
+/// assert_eq!(
+///     summary.format(BpsStyle::Decimal).to_string(),
+///     "1.05 MB in 1.00s (1.05 MB/s avg)"
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default, Hash, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Summary {
+    /// Total bytes transferred.
+    pub bytes: u64,
+    /// Total time elapsed.
+    pub elapsed: Duration,
+}
+
+impl Summary {
+    /// The average rate over this summary's elapsed time, as a [`Bps`].
+    ///
+    /// Returns `Bps(0)` if `elapsed` is zero, rather than dividing by zero.
+    #[inline]
+    pub fn average(&self) -> Bps {
+        if self.elapsed.is_zero() {
+            Bps(0)
+        } else {
+            Bps((self.bytes as f64 / self.elapsed.as_secs_f64()) as u64)
         }
     }
+
+    /// Formats this summary's total and average rate using the given [`BpsStyle`], instead of
+    /// the default binary-unit `Display` impl.
+    #[inline]
+    pub fn format(self, style: BpsStyle) -> FormattedSummary {
+        FormattedSummary(self, style)
+    }
+}
+
+impl Display for Summary {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.format(BpsStyle::Binary).fmt(f)
+    }
+}
+
+/// A [`Display`] adapter that formats a [`Summary`] in a particular [`BpsStyle`].
+///
+/// Created by [`Summary::format`].
+#[derive(Copy, Clone, Debug)]
+pub struct FormattedSummary(Summary, BpsStyle);
+
+impl Display for FormattedSummary {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (base, units) = self.1.base_and_units();
+        format_amount(f, self.1.amount(self.0.bytes), base, units)?;
+        write!(
+            f,
+            " in {:.2}s ({} avg)",
+            self.0.elapsed.as_secs_f64(),
+            self.0.average().format(self.1)
+        )
+    }
 }
 
 #[inline]
-fn bytes_per_second(new: usize, sum: &mut u64, instant: &mut Instant, mut f: impl FnMut(Bps)) {
+fn bytes_per_second<C: Clock>(
+    new: usize,
+    sum: &mut u64,
+    clock: &mut C,
+    interval: Duration,
+    mut f: impl FnMut(Bps),
+) {
     *sum += new as u64;
-    let elapsed = instant.elapsed();
-    if elapsed >= get() {
-        *instant = Instant::now();
+    let elapsed = clock.elapsed();
+    if elapsed >= interval {
+        *clock = C::now();
         f(Bps((*sum as f64 / elapsed.as_secs_f64()) as u64));
         *sum = 0;
     }
 }
 
+/// Like [`bytes_per_second`], but smooths the reported rate with an exponentially-weighted
+/// moving average instead of reporting the raw per-interval rate.
+///
+/// `smoothed` is `None` until the first tick, which seeds it with that tick's instantaneous
+/// rate so reporting doesn't ramp up slowly from zero.
+///
+/// Requires the `std` feature: `f64::exp` is only available through `std`'s platform `libm`
+/// binding, `core` has no transcendental functions of its own.
+#[cfg(feature = "std")]
+#[inline]
+fn bytes_per_second_ewma<C: Clock>(
+    new: usize,
+    sum: &mut u64,
+    clock: &mut C,
+    interval: Duration,
+    tau: Duration,
+    smoothed: &mut Option<f64>,
+    mut f: impl FnMut(Bps),
+) {
+    *sum += new as u64;
+    let elapsed = clock.elapsed();
+    if elapsed >= interval {
+        *clock = C::now();
+        let elapsed_secs = elapsed.as_secs_f64();
+        let instantaneous = *sum as f64 / elapsed_secs;
+        let rate = match *smoothed {
+            Some(previous) => {
+                let alpha = 1.0 - (-elapsed_secs / tau.as_secs_f64()).exp();
+                alpha * instantaneous + (1.0 - alpha) * previous
+            }
+            None => instantaneous,
+        };
+        *smoothed = Some(rate);
+        f(Bps(rate as u64));
+        *sum = 0;
+    }
+}
+
 /// Adapter functions for iterators.
 ///
 /// The functions maps the input iterator and extends it with the ability to report their
 /// throughput every second to the specified receiver.
+///
+/// Requires the `std` feature, since it is built on `mpsc` and thread-local reporting.
+#[cfg(feature = "std")]
 pub mod iter {
     use crate::Bps;
     use std::iter::Map;
-    use std::mem;
     use std::sync::mpsc::Sender;
     use std::time::Instant;
 
@@ -203,7 +602,13 @@ pub mod iter {
         let mut bytes = 0;
         let mut instant = Instant::now();
         iter.into_iter().map(move |item| {
-            crate::bytes_per_second(mem::size_of_val(&item), &mut bytes, &mut instant, &mut slot);
+            crate::bytes_per_second(
+                size_of_val(&item),
+                &mut bytes,
+                &mut instant,
+                crate::get(),
+                &mut slot,
+            );
             item
         })
     }
@@ -215,34 +620,125 @@ pub mod iter {
 /// implementations to be able to report their throughput every second.
 /// If any other methods on the reader has been specialized to not use one of the above methods,
 /// this reader will not report anything.
+///
+/// Without the `std` feature, this module is backed by [`crate::io`]'s `Read` trait instead of
+/// `std::io::Read`, and only [`slot`] is available, taking an explicit interval and
+/// [`Clock`](crate::Clock) type.
 pub mod read {
-    use crate::Bps;
-    use std::io::{self, IoSliceMut, Read};
+    use crate::{Bps, Clock};
+
+    #[cfg(feature = "std")]
+    use std::io::{self, BufRead, IoSliceMut, Read, Seek, SeekFrom};
+    #[cfg(feature = "std")]
     use std::sync::mpsc::Sender;
-    use std::time::Instant;
+    #[cfg(feature = "std")]
+    use std::time::{Duration, Instant};
+
+    #[cfg(not(feature = "std"))]
+    use crate::io::{self, IoSliceMut, Read};
+    #[cfg(not(feature = "std"))]
+    use core::time::Duration;
 
     /// A reader that extends the `read` and `read_vectored` implementations to
     /// report their throughput every second.
     #[derive(Copy, Clone, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
-    struct Reader<R, F> {
+    pub struct Reader<R, F, C> {
         reader: R,
         slot: F,
         bytes: u64,
-        instant: Instant,
+        clock: C,
+        #[cfg(not(feature = "std"))]
+        interval: Duration,
+    }
+
+    impl<R, F, C> Reader<R, F, C> {
+        /// Gets a reference to the underlying reader.
+        #[inline]
+        pub fn get_ref(&self) -> &R {
+            &self.reader
+        }
+
+        /// Gets a mutable reference to the underlying reader.
+        ///
+        /// It is inadvisable to directly read from the underlying reader, since doing so
+        /// bypasses the throughput reporting.
+        #[inline]
+        pub fn get_mut(&mut self) -> &mut R {
+            &mut self.reader
+        }
+
+        /// Unwraps this `Reader`, returning the underlying reader.
+        #[inline]
+        pub fn into_inner(self) -> R {
+            self.reader
+        }
+
+        /// The interval between ticks: read live from [`crate::get`] with the `std` feature, so
+        /// that [`crate::set`] keeps affecting readers built before the call, or the fixed
+        /// value passed to the non-`std` [`slot`] constructor otherwise.
+        #[cfg(feature = "std")]
+        #[inline]
+        fn interval(&self) -> Duration {
+            crate::get()
+        }
+
+        #[cfg(not(feature = "std"))]
+        #[inline]
+        fn interval(&self) -> Duration {
+            self.interval
+        }
+    }
+
+    // `Seek` and `BufRead` are passthroughs with nothing to report, and both are only
+    // implemented under `std`: `crate::io` has no `Seek`, and `Reader` does not implement
+    // `crate::io::BufRead`.
+    #[cfg(feature = "std")]
+    impl<R: Seek, F, C> Seek for Reader<R, F, C> {
+        #[inline]
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.reader.seek(pos)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl<R: BufRead, F: FnMut(Bps), C: Clock> BufRead for Reader<R, F, C> {
+        #[inline]
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            self.reader.fill_buf()
+        }
+
+        #[inline]
+        fn consume(&mut self, amt: usize) {
+            self.reader.consume(amt)
+        }
     }
 
-    impl<R: Read, F: FnMut(Bps)> Read for Reader<R, F> {
+    impl<R: Read, F: FnMut(Bps), C: Clock> Read for Reader<R, F, C> {
         #[inline]
         fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
             let bytes = self.reader.read(buf)?;
-            crate::bytes_per_second(bytes, &mut self.bytes, &mut self.instant, &mut self.slot);
+            let interval = self.interval();
+            crate::bytes_per_second(
+                bytes,
+                &mut self.bytes,
+                &mut self.clock,
+                interval,
+                &mut self.slot,
+            );
             Ok(bytes)
         }
 
         #[inline]
         fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
             let bytes = self.reader.read_vectored(bufs)?;
-            crate::bytes_per_second(bytes, &mut self.bytes, &mut self.instant, &mut self.slot);
+            let interval = self.interval();
+            crate::bytes_per_second(
+                bytes,
+                &mut self.bytes,
+                &mut self.clock,
+                interval,
+                &mut self.slot,
+            );
             Ok(bytes)
         }
     }
@@ -257,6 +753,7 @@ pub mod read {
     ///     io::copy(&mut nyx::read::stdout(io::repeat(0)), &mut io::sink()).unwrap();
     /// }
     /// ```
+    #[cfg(feature = "std")]
     #[inline]
     pub fn stdout(reader: impl Read) -> impl Read {
         slot(reader, |bps| println!("{}", bps))
@@ -272,6 +769,7 @@ pub mod read {
     ///     io::copy(&mut nyx::read::stderr(io::repeat(0)), &mut io::sink()).unwrap();
     /// }
     /// ```
+    #[cfg(feature = "std")]
     #[inline]
     pub fn stderr(reader: impl Read) -> impl Read {
         slot(reader, |bps| eprintln!("{}", bps))
@@ -299,6 +797,7 @@ pub mod read {
     ///         .for_each(|bps| println!("B/s from thread: {}", bps));
     /// }
     /// ```
+    #[cfg(feature = "std")]
     #[inline]
     pub fn send(reader: impl Read, sender: Sender<Bps>) -> impl Read {
         slot(reader, move |bps| {
@@ -320,76 +819,677 @@ pub mod read {
     ///     .unwrap();
     /// }
     /// ```
+    #[cfg(feature = "std")]
     #[inline]
-    pub fn slot(reader: impl Read, slot: impl FnMut(Bps)) -> impl Read {
+    pub fn slot(reader: impl Read, slot: impl FnMut(Bps)) -> Reader<impl Read, impl FnMut(Bps), Instant> {
         Reader {
             reader,
             slot,
             bytes: 0,
-            instant: Instant::now(),
+            clock: Instant::now(),
         }
     }
-}
 
-/// Adapter functions for writers.
-///
-/// The functions returns a new writer that extends the `write` and `write_vectored`
-/// implementations to be able to report their throughput every second.
-/// If any other methods on the writer has been specialized to not use one of the above methods,
-/// this writer will not report anything.
-pub mod write {
-    use crate::Bps;
-    use std::io::{self, IoSlice, Write};
-    use std::sync::mpsc::Sender;
-    use std::time::Instant;
+    /// Creates a reader that yields the bytes by calling the provided slot every `interval`,
+    /// timed using `C`.
+    ///
+    /// This is the only constructor available without the `std` feature, since there is no
+    /// thread-local default interval and no built-in monotonic clock to fall back on.
+    ///
+    /// # Examples
+    /// ```
+    /// # use nyx::Clock;
+    /// # use core::time::Duration;
+    /// # #[derive(Copy, Clone)]
+    /// # struct FakeClock;
+    /// # impl Clock for FakeClock {
+    /// #     fn now() -> Self { FakeClock }
+    /// #     fn elapsed(&self) -> Duration { Duration::from_secs(0) }
+    /// # }
+    /// struct Empty;
+    ///
+    /// impl nyx::io::Read for Empty {
+    ///     fn read(&mut self, _buf: &mut [u8]) -> nyx::io::Result<usize> {
+    ///         Ok(0)
+    ///     }
+    /// }
+    ///
+    /// let mut reader = nyx::read::slot::<_, _, FakeClock>(
+    ///     Empty,
+    ///     Duration::from_secs(1),
+    ///     |_bps| {},
+    /// );
+    /// ```
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    pub fn slot<R, F, C>(reader: R, interval: Duration, slot: F) -> Reader<R, F, C>
+    where
+        R: Read,
+        F: FnMut(Bps),
+        C: Clock,
+    {
+        Reader {
+            reader,
+            slot,
+            bytes: 0,
+            clock: C::now(),
+            interval,
+        }
+    }
 
-    /// A writer that extends the `write` and `write_vectored` implementations to be able to
-    /// report their throughput every second.
-    #[derive(Copy, Clone, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
-    struct Writer<W, F> {
-        writer: W,
+    /// A reader that reports an EWMA-smoothed rate instead of the raw per-interval rate.
+    ///
+    /// See [`slot_ewma`] and [`crate::set_tau`].
+    #[cfg(feature = "std")]
+    #[derive(Clone, Debug)]
+    pub struct EwmaReader<R, F, C> {
+        reader: R,
         slot: F,
         bytes: u64,
-        instant: Instant,
+        clock: C,
+        smoothed: Option<f64>,
     }
 
-    impl<W: Write, F: FnMut(Bps)> Write for Writer<W, F> {
+    #[cfg(feature = "std")]
+    impl<R, F, C> EwmaReader<R, F, C> {
+        /// Gets a reference to the underlying reader.
         #[inline]
-        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-            let bytes = self.writer.write(buf)?;
-            crate::bytes_per_second(bytes, &mut self.bytes, &mut self.instant, &mut self.slot);
-            Ok(bytes)
+        pub fn get_ref(&self) -> &R {
+            &self.reader
         }
 
+        /// Gets a mutable reference to the underlying reader.
+        ///
+        /// It is inadvisable to directly read from the underlying reader, since doing so
+        /// bypasses the throughput reporting.
         #[inline]
-        fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
-            let bytes = self.writer.write_vectored(bufs)?;
-            crate::bytes_per_second(bytes, &mut self.bytes, &mut self.instant, &mut self.slot);
+        pub fn get_mut(&mut self) -> &mut R {
+            &mut self.reader
+        }
+
+        /// Unwraps this `EwmaReader`, returning the underlying reader.
+        #[inline]
+        pub fn into_inner(self) -> R {
+            self.reader
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl<R: Seek, F, C> Seek for EwmaReader<R, F, C> {
+        #[inline]
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.reader.seek(pos)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl<R: BufRead, F: FnMut(Bps), C: Clock> BufRead for EwmaReader<R, F, C> {
+        #[inline]
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            self.reader.fill_buf()
+        }
+
+        #[inline]
+        fn consume(&mut self, amt: usize) {
+            self.reader.consume(amt)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl<R: Read, F: FnMut(Bps), C: Clock> Read for EwmaReader<R, F, C> {
+        #[inline]
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let bytes = self.reader.read(buf)?;
+            crate::bytes_per_second_ewma(
+                bytes,
+                &mut self.bytes,
+                &mut self.clock,
+                crate::get(),
+                crate::tau(),
+                &mut self.smoothed,
+                &mut self.slot,
+            );
             Ok(bytes)
         }
 
         #[inline]
-        fn flush(&mut self) -> io::Result<()> {
-            self.writer.flush()
+        fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+            let bytes = self.reader.read_vectored(bufs)?;
+            crate::bytes_per_second_ewma(
+                bytes,
+                &mut self.bytes,
+                &mut self.clock,
+                crate::get(),
+                crate::tau(),
+                &mut self.smoothed,
+                &mut self.slot,
+            );
+            Ok(bytes)
         }
     }
 
-    /// Creates a writer that yields the bytes by printing it to `stdout`.
+    /// Creates a reader that yields an EWMA-smoothed rate by calling the provided slot, instead
+    /// of the raw rate measured over each interval.
+    ///
+    /// The time constant defaults to three seconds and is configured per-thread through
+    /// [`crate::set_tau`], mirroring how the update interval is configured through
+    /// [`crate::set`].
     ///
     /// # Examples
     /// ```no_run
     /// use std::io;
     ///
     /// fn main() {
-    ///     io::copy(&mut io::repeat(0), &mut nyx::write::stdout(io::sink())).unwrap();
+    ///     io::copy(
+    ///         &mut nyx::read::slot_ewma(io::repeat(0), |bps| println!("B/s: {}", bps)),
+    ///         &mut io::sink(),
+    ///     )
+    ///     .unwrap();
     /// }
     /// ```
+    #[cfg(feature = "std")]
     #[inline]
-    pub fn stdout(writer: impl Write) -> impl Write {
-        slot(writer, |bps| println!("{}", bps))
+    pub fn slot_ewma(
+        reader: impl Read,
+        slot: impl FnMut(Bps),
+    ) -> EwmaReader<impl Read, impl FnMut(Bps), Instant> {
+        EwmaReader {
+            reader,
+            slot,
+            bytes: 0,
+            clock: Instant::now(),
+            smoothed: None,
+        }
     }
+}
 
-    /// Creates a writer that yields the bytes by printing it to `stderr`.
+/// Adapter functions for buffered readers.
+///
+/// The `read` module only intercepts `read`/`read_vectored`, so anything driven through
+/// `fill_buf`/`consume` instead — `read_line`, `read_until`, or a `BufRead` consumer in
+/// general — reports nothing. The functions here wrap any `BufRead` and report throughput
+/// based on bytes actually consumed: `fill_buf` is forwarded unchanged, and `consume(amt)`
+/// feeds `amt` into the throughput calculation. The wrapper also implements `Read`, delegating
+/// straight through, so it remains a drop-in for either trait.
+///
+/// Without the `std` feature, this module is backed by [`crate::io`]'s `BufRead`/`Read` traits
+/// instead of `std::io`'s, and only [`slot`] is available, taking an explicit interval and
+/// [`Clock`](crate::Clock) type.
+pub mod bufread {
+    use crate::{Bps, Clock};
+
+    #[cfg(feature = "std")]
+    use std::io::{self, BufRead, IoSliceMut, Read, Seek, SeekFrom};
+    #[cfg(feature = "std")]
+    use std::sync::mpsc::Sender;
+    #[cfg(feature = "std")]
+    use std::time::{Duration, Instant};
+
+    #[cfg(not(feature = "std"))]
+    use core::time::Duration;
+    #[cfg(not(feature = "std"))]
+    use crate::io::{self, BufRead, IoSliceMut, Read};
+
+    /// A `BufRead` that extends `consume` to report the throughput of bytes actually consumed
+    /// every second.
+    #[derive(Copy, Clone, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
+    pub struct BufReader<R, F, C> {
+        reader: R,
+        slot: F,
+        bytes: u64,
+        clock: C,
+        #[cfg(not(feature = "std"))]
+        interval: Duration,
+    }
+
+    impl<R, F, C> BufReader<R, F, C> {
+        /// Gets a reference to the underlying reader.
+        #[inline]
+        pub fn get_ref(&self) -> &R {
+            &self.reader
+        }
+
+        /// Gets a mutable reference to the underlying reader.
+        ///
+        /// It is inadvisable to directly read from the underlying reader, since doing so
+        /// bypasses the throughput reporting.
+        #[inline]
+        pub fn get_mut(&mut self) -> &mut R {
+            &mut self.reader
+        }
+
+        /// Unwraps this `BufReader`, returning the underlying reader.
+        #[inline]
+        pub fn into_inner(self) -> R {
+            self.reader
+        }
+
+        /// The interval between ticks: read live from [`crate::get`] with the `std` feature, so
+        /// that [`crate::set`] keeps affecting readers built before the call, or the fixed
+        /// value passed to the non-`std` [`slot`] constructor otherwise.
+        #[cfg(feature = "std")]
+        #[inline]
+        fn interval(&self) -> Duration {
+            crate::get()
+        }
+
+        #[cfg(not(feature = "std"))]
+        #[inline]
+        fn interval(&self) -> Duration {
+            self.interval
+        }
+    }
+
+    // `Seek` is a passthrough with nothing to report, so it is only implemented under `std`:
+    // `crate::io` does not provide it.
+    #[cfg(feature = "std")]
+    impl<R: Seek, F, C> Seek for BufReader<R, F, C> {
+        #[inline]
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.reader.seek(pos)
+        }
+    }
+
+    impl<R: Read, F, C> Read for BufReader<R, F, C> {
+        #[inline]
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.reader.read(buf)
+        }
+
+        #[inline]
+        fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+            self.reader.read_vectored(bufs)
+        }
+    }
+
+    impl<R: BufRead, F: FnMut(Bps), C: Clock> BufRead for BufReader<R, F, C> {
+        #[inline]
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            self.reader.fill_buf()
+        }
+
+        #[inline]
+        fn consume(&mut self, amt: usize) {
+            self.reader.consume(amt);
+            let interval = self.interval();
+            crate::bytes_per_second(
+                amt,
+                &mut self.bytes,
+                &mut self.clock,
+                interval,
+                &mut self.slot,
+            );
+        }
+    }
+
+    /// Creates a buffered reader that yields the bytes by printing it to `stdout`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::io::{self, BufRead, BufReader};
+    ///
+    /// fn main() {
+    ///     let mut lines = String::new();
+    ///     nyx::bufread::stdout(BufReader::new(io::repeat(b'\n')))
+    ///         .read_line(&mut lines)
+    ///         .unwrap();
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn stdout(reader: impl BufRead) -> impl BufRead {
+        slot(reader, |bps| println!("{}", bps))
+    }
+
+    /// Creates a buffered reader that yields the bytes by printing it to `stderr`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::io::{self, BufRead, BufReader};
+    ///
+    /// fn main() {
+    ///     let mut lines = String::new();
+    ///     nyx::bufread::stderr(BufReader::new(io::repeat(b'\n')))
+    ///         .read_line(&mut lines)
+    ///         .unwrap();
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn stderr(reader: impl BufRead) -> impl BufRead {
+        slot(reader, |bps| eprintln!("{}", bps))
+    }
+
+    /// Creates a buffered reader that yields the bytes by sending it through the provided
+    /// `Sender`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::io::{self, BufRead, BufReader};
+    /// use std::sync::mpsc;
+    /// use std::thread;
+    ///
+    /// fn main() {
+    ///     let (sender, receiver) = mpsc::channel();
+    ///     thread::spawn(move || {
+    ///         let mut lines = String::new();
+    ///         nyx::bufread::send(BufReader::new(io::repeat(b'\n')), sender)
+    ///             .read_line(&mut lines)
+    ///             .unwrap();
+    ///     });
+    ///     receiver
+    ///         .iter()
+    ///         .for_each(|bps| println!("B/s from thread: {}", bps));
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn send(reader: impl BufRead, sender: Sender<Bps>) -> impl BufRead {
+        slot(reader, move |bps| {
+            let _ = sender.send(bps);
+        })
+    }
+
+    /// Creates a buffered reader that yields the bytes by calling the provided slot.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::io::{self, BufRead, BufReader};
+    ///
+    /// fn main() {
+    ///     let mut lines = String::new();
+    ///     nyx::bufread::slot(BufReader::new(io::repeat(b'\n')), |bps| println!("B/s: {}", bps))
+    ///         .read_line(&mut lines)
+    ///         .unwrap();
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn slot(
+        reader: impl BufRead,
+        slot: impl FnMut(Bps),
+    ) -> BufReader<impl BufRead, impl FnMut(Bps), Instant> {
+        BufReader {
+            reader,
+            slot,
+            bytes: 0,
+            clock: Instant::now(),
+        }
+    }
+
+    /// Creates a buffered reader that yields the bytes by calling the provided slot every
+    /// `interval`, timed using `C`.
+    ///
+    /// This is the only constructor available without the `std` feature, since there is no
+    /// thread-local default interval and no built-in monotonic clock to fall back on.
+    #[cfg(not(feature = "std"))]
+    #[inline]
+    pub fn slot<R, F, C>(reader: R, interval: Duration, slot: F) -> BufReader<R, F, C>
+    where
+        R: BufRead,
+        F: FnMut(Bps),
+        C: Clock,
+    {
+        BufReader {
+            reader,
+            slot,
+            bytes: 0,
+            clock: C::now(),
+            interval,
+        }
+    }
+
+    /// A `BufRead` that reports an EWMA-smoothed rate instead of the raw per-interval rate.
+    ///
+    /// See [`slot_ewma`] and [`crate::set_tau`].
+    #[cfg(feature = "std")]
+    #[derive(Clone, Debug)]
+    pub struct EwmaBufReader<R, F, C> {
+        reader: R,
+        slot: F,
+        bytes: u64,
+        clock: C,
+        smoothed: Option<f64>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<R, F, C> EwmaBufReader<R, F, C> {
+        /// Gets a reference to the underlying reader.
+        #[inline]
+        pub fn get_ref(&self) -> &R {
+            &self.reader
+        }
+
+        /// Gets a mutable reference to the underlying reader.
+        ///
+        /// It is inadvisable to directly read from the underlying reader, since doing so
+        /// bypasses the throughput reporting.
+        #[inline]
+        pub fn get_mut(&mut self) -> &mut R {
+            &mut self.reader
+        }
+
+        /// Unwraps this `EwmaBufReader`, returning the underlying reader.
+        #[inline]
+        pub fn into_inner(self) -> R {
+            self.reader
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl<R: Seek, F, C> Seek for EwmaBufReader<R, F, C> {
+        #[inline]
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.reader.seek(pos)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl<R: Read, F, C> Read for EwmaBufReader<R, F, C> {
+        #[inline]
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.reader.read(buf)
+        }
+
+        #[inline]
+        fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+            self.reader.read_vectored(bufs)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl<R: BufRead, F: FnMut(Bps), C: Clock> BufRead for EwmaBufReader<R, F, C> {
+        #[inline]
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            self.reader.fill_buf()
+        }
+
+        #[inline]
+        fn consume(&mut self, amt: usize) {
+            self.reader.consume(amt);
+            crate::bytes_per_second_ewma(
+                amt,
+                &mut self.bytes,
+                &mut self.clock,
+                crate::get(),
+                crate::tau(),
+                &mut self.smoothed,
+                &mut self.slot,
+            );
+        }
+    }
+
+    /// Creates a buffered reader that yields an EWMA-smoothed rate by calling the provided slot,
+    /// instead of the raw rate measured over each interval.
+    ///
+    /// The time constant defaults to three seconds and is configured per-thread through
+    /// [`crate::set_tau`], mirroring how the update interval is configured through
+    /// [`crate::set`].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::io::{self, BufRead, BufReader};
+    ///
+    /// fn main() {
+    ///     let mut lines = String::new();
+    ///     nyx::bufread::slot_ewma(BufReader::new(io::repeat(b'\n')), |bps| println!("B/s: {}", bps))
+    ///         .read_line(&mut lines)
+    ///         .unwrap();
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn slot_ewma(
+        reader: impl BufRead,
+        slot: impl FnMut(Bps),
+    ) -> EwmaBufReader<impl BufRead, impl FnMut(Bps), Instant> {
+        EwmaBufReader {
+            reader,
+            slot,
+            bytes: 0,
+            clock: Instant::now(),
+            smoothed: None,
+        }
+    }
+}
+
+/// Adapter functions for writers.
+///
+/// The functions returns a new writer that extends the `write` and `write_vectored`
+/// implementations to be able to report their throughput every second.
+/// If any other methods on the writer has been specialized to not use one of the above methods,
+/// this writer will not report anything.
+///
+/// Without the `std` feature, this module is backed by [`crate::io`]'s `Write` trait instead of
+/// `std::io::Write`, and only [`slot`] is available, taking an explicit interval and
+/// [`Clock`](crate::Clock) type.
+pub mod write {
+    use crate::{Bps, Clock};
+
+    #[cfg(feature = "std")]
+    use std::io::{self, IoSlice, Seek, SeekFrom, Write};
+    #[cfg(feature = "std")]
+    use std::sync::mpsc::Sender;
+    #[cfg(feature = "std")]
+    use std::time::{Duration, Instant};
+
+    #[cfg(not(feature = "std"))]
+    use crate::io::{self, IoSlice, Write};
+    #[cfg(not(feature = "std"))]
+    use core::time::Duration;
+
+    /// A writer that extends the `write` and `write_vectored` implementations to be able to
+    /// report their throughput every second.
+    #[derive(Copy, Clone, Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
+    pub struct Writer<W, F, C> {
+        writer: W,
+        slot: F,
+        bytes: u64,
+        clock: C,
+        #[cfg(not(feature = "std"))]
+        interval: Duration,
+    }
+
+    impl<W, F, C> Writer<W, F, C> {
+        /// Gets a reference to the underlying writer.
+        #[inline]
+        pub fn get_ref(&self) -> &W {
+            &self.writer
+        }
+
+        /// Gets a mutable reference to the underlying writer.
+        ///
+        /// It is inadvisable to directly write to the underlying writer, since doing so
+        /// bypasses the throughput reporting.
+        #[inline]
+        pub fn get_mut(&mut self) -> &mut W {
+            &mut self.writer
+        }
+
+        /// Unwraps this `Writer`, returning the underlying writer.
+        #[inline]
+        pub fn into_inner(self) -> W {
+            self.writer
+        }
+
+        /// The interval between ticks: read live from [`crate::get`] with the `std` feature, so
+        /// that [`crate::set`] keeps affecting writers built before the call, or the fixed
+        /// value passed to the non-`std` [`slot`] constructor otherwise.
+        #[cfg(feature = "std")]
+        #[inline]
+        fn interval(&self) -> Duration {
+            crate::get()
+        }
+
+        #[cfg(not(feature = "std"))]
+        #[inline]
+        fn interval(&self) -> Duration {
+            self.interval
+        }
+    }
+
+    // `Seek` is a passthrough with nothing to report, so it is only implemented under `std`:
+    // `crate::io` does not provide it.
+    #[cfg(feature = "std")]
+    impl<W: Seek, F, C> Seek for Writer<W, F, C> {
+        #[inline]
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.writer.seek(pos)
+        }
+    }
+
+    impl<W: Write, F: FnMut(Bps), C: Clock> Write for Writer<W, F, C> {
+        #[inline]
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let bytes = self.writer.write(buf)?;
+            let interval = self.interval();
+            crate::bytes_per_second(
+                bytes,
+                &mut self.bytes,
+                &mut self.clock,
+                interval,
+                &mut self.slot,
+            );
+            Ok(bytes)
+        }
+
+        #[inline]
+        fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+            let bytes = self.writer.write_vectored(bufs)?;
+            let interval = self.interval();
+            crate::bytes_per_second(
+                bytes,
+                &mut self.bytes,
+                &mut self.clock,
+                interval,
+                &mut self.slot,
+            );
+            Ok(bytes)
+        }
+
+        #[inline]
+        fn flush(&mut self) -> io::Result<()> {
+            self.writer.flush()
+        }
+    }
+
+    /// Creates a writer that yields the bytes by printing it to `stdout`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::io;
+    ///
+    /// fn main() {
+    ///     io::copy(&mut io::repeat(0), &mut nyx::write::stdout(io::sink())).unwrap();
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn stdout(writer: impl Write) -> impl Write {
+        slot(writer, |bps| println!("{}", bps))
+    }
+
+    /// Creates a writer that yields the bytes by printing it to `stderr`.
     ///
     /// # Examples
     /// ```no_run
@@ -399,6 +1499,7 @@ pub mod write {
     ///     io::copy(&mut io::repeat(0), &mut nyx::write::stderr(io::sink())).unwrap();
     /// }
     /// ```
+    #[cfg(feature = "std")]
     #[inline]
     pub fn stderr(writer: impl Write) -> impl Write {
         slot(writer, |bps| eprintln!("{}", bps))
@@ -426,6 +1527,7 @@ pub mod write {
     ///         .for_each(|bps| println!("B/s from thread: {}", bps));
     /// }
     /// ```
+    #[cfg(feature = "std")]
     #[inline]
     pub fn send(writer: impl Write, sender: Sender<Bps>) -> impl Write {
         slot(writer, move |bps| {
@@ -447,13 +1549,368 @@ pub mod write {
     ///     .unwrap();
     /// }
     /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn slot(writer: impl Write, slot: impl FnMut(Bps)) -> Writer<impl Write, impl FnMut(Bps), Instant> {
+        Writer {
+            writer,
+            slot,
+            bytes: 0,
+            clock: Instant::now(),
+        }
+    }
+
+    /// Creates a writer that yields the bytes by calling the provided slot every `interval`,
+    /// timed using `C`.
+    ///
+    /// This is the only constructor available without the `std` feature, since there is no
+    /// thread-local default interval and no built-in monotonic clock to fall back on.
+    #[cfg(not(feature = "std"))]
     #[inline]
-    pub fn slot(writer: impl Write, slot: impl FnMut(Bps)) -> impl Write {
+    pub fn slot<W, F, C>(writer: W, interval: Duration, slot: F) -> Writer<W, F, C>
+    where
+        W: Write,
+        F: FnMut(Bps),
+        C: Clock,
+    {
         Writer {
             writer,
             slot,
             bytes: 0,
-            instant: Instant::now(),
+            clock: C::now(),
+            interval,
+        }
+    }
+
+    /// A writer that reports an EWMA-smoothed rate instead of the raw per-interval rate.
+    ///
+    /// See [`slot_ewma`] and [`crate::set_tau`].
+    #[cfg(feature = "std")]
+    #[derive(Clone, Debug)]
+    pub struct EwmaWriter<W, F, C> {
+        writer: W,
+        slot: F,
+        bytes: u64,
+        clock: C,
+        smoothed: Option<f64>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<W, F, C> EwmaWriter<W, F, C> {
+        /// Gets a reference to the underlying writer.
+        #[inline]
+        pub fn get_ref(&self) -> &W {
+            &self.writer
+        }
+
+        /// Gets a mutable reference to the underlying writer.
+        ///
+        /// It is inadvisable to directly write to the underlying writer, since doing so
+        /// bypasses the throughput reporting.
+        #[inline]
+        pub fn get_mut(&mut self) -> &mut W {
+            &mut self.writer
+        }
+
+        /// Unwraps this `EwmaWriter`, returning the underlying writer.
+        #[inline]
+        pub fn into_inner(self) -> W {
+            self.writer
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl<W: Seek, F, C> Seek for EwmaWriter<W, F, C> {
+        #[inline]
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.writer.seek(pos)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl<W: Write, F: FnMut(Bps), C: Clock> Write for EwmaWriter<W, F, C> {
+        #[inline]
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let bytes = self.writer.write(buf)?;
+            crate::bytes_per_second_ewma(
+                bytes,
+                &mut self.bytes,
+                &mut self.clock,
+                crate::get(),
+                crate::tau(),
+                &mut self.smoothed,
+                &mut self.slot,
+            );
+            Ok(bytes)
+        }
+
+        #[inline]
+        fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+            let bytes = self.writer.write_vectored(bufs)?;
+            crate::bytes_per_second_ewma(
+                bytes,
+                &mut self.bytes,
+                &mut self.clock,
+                crate::get(),
+                crate::tau(),
+                &mut self.smoothed,
+                &mut self.slot,
+            );
+            Ok(bytes)
+        }
+
+        #[inline]
+        fn flush(&mut self) -> io::Result<()> {
+            self.writer.flush()
+        }
+    }
+
+    /// Creates a writer that yields an EWMA-smoothed rate by calling the provided slot, instead
+    /// of the raw rate measured over each interval.
+    ///
+    /// The time constant defaults to three seconds and is configured per-thread through
+    /// [`crate::set_tau`], mirroring how the update interval is configured through
+    /// [`crate::set`].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::io;
+    ///
+    /// fn main() {
+    ///     io::copy(
+    ///         &mut io::repeat(0),
+    ///         &mut nyx::write::slot_ewma(io::sink(), |bps| println!("B/s: {}", bps)),
+    ///     )
+    ///     .unwrap();
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn slot_ewma(
+        writer: impl Write,
+        slot: impl FnMut(Bps),
+    ) -> EwmaWriter<impl Write, impl FnMut(Bps), Instant> {
+        EwmaWriter {
+            writer,
+            slot,
+            bytes: 0,
+            clock: Instant::now(),
+            smoothed: None,
+        }
+    }
+}
+
+/// A throughput-reporting replacement for `std::io::copy`.
+///
+/// `io::copy` can specialize past a wrapped reader's or writer's `read`/`write` methods (and on
+/// some platforms drives the transfer through `sendfile`/`copy_file_range`), so wrapping either
+/// side with [`read`] or [`write`] can silently report nothing. The functions here own the copy
+/// loop instead: they allocate an internal buffer, repeatedly `read` into it and `write_all` out
+/// of it, and feed each chunk's length into the throughput calculation, which reports the rate
+/// to the slot once per interval, returning the total bytes copied like `std::io::copy`.
+///
+/// Requires the `std` feature, since it is built on `std::io::copy`'s API and panics on an
+/// allocation failure for the internal buffer, which is only sound to do with `std` present.
+#[cfg(feature = "std")]
+pub mod copy {
+    use crate::{Bps, Summary};
+    use std::io::{self, Read, Write};
+    use std::sync::mpsc::Sender;
+    use std::time::Instant;
+
+    /// The default size, in bytes, of the buffer used to drive the copy loop.
+    const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+    /// Copies `reader` into `writer`, printing the throughput to `stdout`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::io;
+    ///
+    /// fn main() {
+    ///     nyx::copy::stdout(io::repeat(0), io::sink()).unwrap();
+    /// }
+    /// ```
+    #[inline]
+    pub fn stdout(reader: impl Read, writer: impl Write) -> io::Result<u64> {
+        slot(reader, writer, |bps| println!("{}", bps))
+    }
+
+    /// Copies `reader` into `writer`, printing the throughput to `stderr`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::io;
+    ///
+    /// fn main() {
+    ///     nyx::copy::stderr(io::repeat(0), io::sink()).unwrap();
+    /// }
+    /// ```
+    #[inline]
+    pub fn stderr(reader: impl Read, writer: impl Write) -> io::Result<u64> {
+        slot(reader, writer, |bps| eprintln!("{}", bps))
+    }
+
+    /// Copies `reader` into `writer`, sending the throughput through the provided `Sender`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::io;
+    /// use std::sync::mpsc;
+    /// use std::thread;
+    ///
+    /// fn main() {
+    ///     let (sender, receiver) = mpsc::channel();
+    ///     thread::spawn(move || {
+    ///         nyx::copy::send(io::repeat(0), io::sink(), sender).unwrap();
+    ///     });
+    ///     receiver
+    ///         .iter()
+    ///         .for_each(|bps| println!("B/s from thread: {}", bps));
+    /// }
+    /// ```
+    #[inline]
+    pub fn send(reader: impl Read, writer: impl Write, sender: Sender<Bps>) -> io::Result<u64> {
+        slot(reader, writer, move |bps| {
+            let _ = sender.send(bps);
+        })
+    }
+
+    /// Copies `reader` into `writer`, calling the provided slot with the throughput.
+    ///
+    /// Uses an 8 KiB buffer; use [`with_capacity`] to configure this.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::io;
+    ///
+    /// fn main() {
+    ///     nyx::copy::slot(io::repeat(0), io::sink(), |bps| println!("B/s: {}", bps)).unwrap();
+    /// }
+    /// ```
+    #[inline]
+    pub fn slot(
+        reader: impl Read,
+        writer: impl Write,
+        slot: impl FnMut(Bps),
+    ) -> io::Result<u64> {
+        with_capacity(DEFAULT_CAPACITY, reader, writer, slot)
+    }
+
+    /// Copies `reader` into `writer` like [`slot`], but with a configurable buffer capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero: `Read::read` conventionally returns `Ok(0)` for a
+    /// zero-length buffer regardless of whether the stream has more data, which this loop would
+    /// otherwise mistake for EOF and return a truncated, zero-byte copy.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::io;
+    ///
+    /// fn main() {
+    ///     nyx::copy::with_capacity(64 * 1024, io::repeat(0), io::sink(), |bps| {
+    ///         println!("B/s: {}", bps)
+    ///     })
+    ///     .unwrap();
+    /// }
+    /// ```
+    pub fn with_capacity(
+        capacity: usize,
+        mut reader: impl Read,
+        mut writer: impl Write,
+        mut slot: impl FnMut(Bps),
+    ) -> io::Result<u64> {
+        assert!(capacity > 0, "capacity must be greater than zero");
+        let mut buf = vec![0; capacity];
+        let mut total = 0;
+        let mut bytes = 0;
+        let mut clock = Instant::now();
+        loop {
+            let read = match reader.read(&mut buf) {
+                Ok(0) => return Ok(total),
+                Ok(read) => read,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            writer.write_all(&buf[..read])?;
+            total += read as u64;
+            crate::bytes_per_second(read, &mut bytes, &mut clock, crate::get(), &mut slot);
+        }
+    }
+
+    /// Copies `reader` into `writer` like [`slot`], returning a [`Summary`] of the total bytes
+    /// and elapsed time instead of a bare byte count.
+    ///
+    /// Uses an 8 KiB buffer; use [`with_capacity_summary`] to configure this.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::io;
+    ///
+    /// fn main() {
+    ///     let summary =
+    ///         nyx::copy::summary(io::repeat(0), io::sink(), |bps| println!("B/s: {}", bps))
+    ///             .unwrap();
+    ///     println!("{}", summary);
+    /// }
+    /// ```
+    #[inline]
+    pub fn summary(
+        reader: impl Read,
+        writer: impl Write,
+        slot: impl FnMut(Bps),
+    ) -> io::Result<Summary> {
+        with_capacity_summary(DEFAULT_CAPACITY, reader, writer, slot)
+    }
+
+    /// Copies `reader` into `writer` like [`with_capacity`], but returns a [`Summary`] of the
+    /// total bytes and elapsed time instead of a bare byte count.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero; see [`with_capacity`].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::io;
+    ///
+    /// fn main() {
+    ///     let summary = nyx::copy::with_capacity_summary(64 * 1024, io::repeat(0), io::sink(), |bps| {
+    ///         println!("B/s: {}", bps)
+    ///     })
+    ///     .unwrap();
+    ///     println!("{}", summary);
+    /// }
+    /// ```
+    pub fn with_capacity_summary(
+        capacity: usize,
+        mut reader: impl Read,
+        mut writer: impl Write,
+        mut slot: impl FnMut(Bps),
+    ) -> io::Result<Summary> {
+        assert!(capacity > 0, "capacity must be greater than zero");
+        let start = Instant::now();
+        let mut buf = vec![0; capacity];
+        let mut total = 0;
+        let mut bytes = 0;
+        let mut clock = start;
+        loop {
+            let read = match reader.read(&mut buf) {
+                Ok(0) => {
+                    return Ok(Summary {
+                        bytes: total,
+                        elapsed: start.elapsed(),
+                    })
+                }
+                Ok(read) => read,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            writer.write_all(&buf[..read])?;
+            total += read as u64;
+            crate::bytes_per_second(read, &mut bytes, &mut clock, crate::get(), &mut slot);
         }
     }
 }